@@ -1,33 +1,47 @@
-use anyhow::Result;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{BufReader, Read};
 
 mod pipeline;
-use pipeline::{BusinessLogicStage, Pipeline, RecordParserStage, Stage};
+use pipeline::{
+    BusinessLogicStage, CompressStage, DecompressStage, FixedU32Be, Gzip, Pipeline,
+    RecordParserStage, RecordWriterStage, Stage, Zstd,
+};
 
-fn main() -> Result<()> {
-    let input_path = "input.bin";
+fn main() -> anyhow::Result<()> {
+    let input_path = "input.bin.gz";
+    let output_path = "output.bin.zst";
 
     // 1. Stage that reads raw bytes from a file
     let reader_stage = FileReaderStage::new(input_path.to_string());
 
-    // 2. Parser → raw bytes → records
-    let parser_stage = RecordParserStage;
+    // 2. Decompress the gzip-framed input
+    let decompress_stage = DecompressStage::<Gzip>::default();
 
-    // 3. Business logic over parsed records
+    // 3. Parser → raw bytes → records
+    let parser_stage = RecordParserStage::<FixedU32Be>::default();
+
+    // 4. Business logic over parsed records
     let logic_stage = BusinessLogicStage;
 
+    // 5. Records → raw bytes
+    let writer_stage = RecordWriterStage::<FixedU32Be>::default();
+
+    // 6. Compress the output into a zstd frame
+    let compress_stage = CompressStage::<Zstd>::default();
+
     // Compose the pipeline
     let pipeline = Pipeline::new(reader_stage)
+        .then(decompress_stage)
         .then(parser_stage)
-        .then(logic_stage);
+        .then(logic_stage)
+        .then(writer_stage)
+        .then(compress_stage);
 
     // Execute pipeline
-    let result: Vec<Vec<u8>> = pipeline.run(())?;
+    let result: Vec<u8> = pipeline.run(())?;
 
-    for (i, rec) in result.iter().enumerate() {
-        println!("Record #{i}: {:?}", String::from_utf8_lossy(rec));
-    }
+    fs::write(output_path, &result)?;
+    println!("Wrote {} compressed byte(s) to {output_path}", result.len());
 
     Ok(())
 }
@@ -44,7 +58,7 @@ impl FileReaderStage {
 }
 
 impl Stage<(), Vec<u8>> for FileReaderStage {
-    fn run(&self, _input: ()) -> Result<Vec<u8>> {
+    fn run(&self, _input: ()) -> pipeline::Result<Vec<u8>> {
         let file = File::open(&self.path)?;
         let mut reader = BufReader::new(file);
         let mut buf = Vec::new();
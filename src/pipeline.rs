@@ -1,5 +1,39 @@
-use anyhow::{anyhow, Result};
 use std::marker::PhantomData;
+use thiserror::Error;
+
+//
+// -------- Errors ----------
+//
+/// Errors produced while parsing or writing the record framing. Every variant
+/// carries the absolute byte `offset` into the original input so callers can
+/// locate corruption in a large file instead of just getting an opaque
+/// string.
+///
+/// `anyhow::Error: From<PipelineError>` already holds via anyhow's blanket
+/// conversion for `std::error::Error` types, so `?` still works in `main`.
+#[derive(Debug, Error)]
+pub enum PipelineError {
+    #[error("truncated record at offset {offset}: expected {expected} bytes, {available} available")]
+    TruncatedRecord {
+        offset: usize,
+        expected: usize,
+        available: usize,
+    },
+
+    #[error("trailing bytes at offset {offset}: {extra} extra byte(s)")]
+    TrailingBytes { offset: usize, extra: usize },
+
+    #[error("invalid length header at offset {offset}")]
+    InvalidLength { offset: usize },
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+pub type Result<T> = std::result::Result<T, PipelineError>;
 
 //
 // -------- Base Stage trait ----------
@@ -11,44 +45,519 @@ pub trait Stage<I, O> {
 
 pub type Record = Vec<u8>;
 
+//
+// ---------- Length Codec ------------
+//
+/// Encodes/decodes the length prefix that precedes each record's payload.
+/// Pulling this out of `RecordParserStage` lets the framing be self-describing
+/// and compact instead of hard-wired to a 4-byte big-endian header.
+pub trait LengthCodec {
+    /// Append the encoded length to `out`.
+    fn encode(len: usize, out: &mut Vec<u8>);
+
+    /// Decode a length from the start of `buf`, returning the decoded value
+    /// and the number of header bytes it occupied. `buf` is a relative view,
+    /// so decode failures are reported without an absolute offset; callers
+    /// attach the offset themselves (see `RecordParserStage`, `parse_views`,
+    /// and `StreamingRecordParser::feed`).
+    fn decode(buf: &[u8]) -> std::result::Result<(usize, usize), DecodeError>;
+}
+
+/// Why a `LengthCodec::decode` call failed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// `buf` doesn't yet hold a complete header; more bytes may resolve it.
+    /// Benign in a streaming context, but a genuine truncation if `buf` is
+    /// known to be the entire remaining input.
+    Incomplete,
+    /// `buf` holds a complete header, but it is malformed and can never
+    /// become valid no matter how much more data arrives.
+    Invalid,
+}
+
+/// Original framing: a fixed 4-byte big-endian length header.
+pub struct FixedU32Be;
+
+impl LengthCodec for FixedU32Be {
+    fn encode(len: usize, out: &mut Vec<u8>) {
+        out.extend_from_slice(&(len as u32).to_be_bytes());
+    }
+
+    fn decode(buf: &[u8]) -> std::result::Result<(usize, usize), DecodeError> {
+        if buf.len() < 4 {
+            return Err(DecodeError::Incomplete);
+        }
+
+        let len = u32::from_be_bytes(buf[..4].try_into().unwrap()) as usize;
+        Ok((len, 4))
+    }
+}
+
+/// Compact framing: an LEB128 varint length header. Each byte contributes its
+/// low 7 bits to the value, with the high bit set as a continuation flag;
+/// decoding stops at the first byte whose high bit is clear.
+pub struct VarintLeb128;
+
+impl LengthCodec for VarintLeb128 {
+    fn encode(len: usize, out: &mut Vec<u8>) {
+        let mut value = len as u64;
+        loop {
+            let mut byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value != 0 {
+                byte |= 0x80;
+            }
+            out.push(byte);
+            if value == 0 {
+                break;
+            }
+        }
+    }
+
+    fn decode(buf: &[u8]) -> std::result::Result<(usize, usize), DecodeError> {
+        let mut value: u64 = 0;
+        let mut i = 0;
+
+        loop {
+            if i >= 10 {
+                return Err(DecodeError::Invalid);
+            }
+            if i >= buf.len() {
+                return Err(DecodeError::Incomplete);
+            }
+
+            let byte = buf[i];
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            i += 1;
+
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        Ok((value as usize, i))
+    }
+}
+
 //
 // ---------- Parser Stage ------------
 //
 /// Format:
-///    [4 bytes length big-endian] [payload]  * repeated
-pub struct RecordParserStage;
+///    [length header, per `C`] [payload]  * repeated
+pub struct RecordParserStage<C = FixedU32Be> {
+    _codec: PhantomData<C>,
+}
+
+impl<C> Default for RecordParserStage<C> {
+    fn default() -> Self {
+        Self {
+            _codec: PhantomData,
+        }
+    }
+}
+
+impl<C> RecordParserStage<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
-impl Stage<Vec<u8>, Vec<Record>> for RecordParserStage {
+impl<C: LengthCodec> Stage<Vec<u8>, Vec<Record>> for RecordParserStage<C> {
     fn run(&self, input: Vec<u8>) -> Result<Vec<Record>> {
         let mut res = Vec::new();
         let mut i = 0;
 
-        while i + 4 <= input.len() {
-            let len_bytes = &input[i..i + 4];
-            let len = u32::from_be_bytes(len_bytes.try_into().unwrap()) as usize;
-            i += 4;
+        while i < input.len() {
+            let (len, header_len) = match C::decode(&input[i..]) {
+                Ok(decoded) => decoded,
+                Err(DecodeError::Incomplete) => break,
+                Err(DecodeError::Invalid) => {
+                    return Err(PipelineError::InvalidLength { offset: i });
+                }
+            };
+            let header_offset = i;
+            i += header_len;
 
-            if i + len > input.len() {
-                return Err(anyhow!(
-                    "truncated record: expected {len} bytes, remaining {}",
-                    input.len() - i
-                ));
-            }
+            let end = match i.checked_add(len) {
+                Some(end) if end <= input.len() => end,
+                _ => {
+                    return Err(PipelineError::TruncatedRecord {
+                        offset: header_offset,
+                        expected: len,
+                        available: input.len() - i,
+                    });
+                }
+            };
 
-            let payload = input[i..i + len].to_vec();
-            i += len;
+            let payload = input[i..end].to_vec();
+            i = end;
 
             res.push(payload);
         }
 
         if i != input.len() {
-            return Err(anyhow!("extra {} bytes at end of input", input.len() - i));
+            return Err(PipelineError::TrailingBytes {
+                offset: i,
+                extra: input.len() - i,
+            });
+        }
+
+        Ok(res)
+    }
+}
+
+/// A borrowed view onto one record's framing, avoiding the `to_vec()` copy
+/// `RecordParserStage::run` makes per record.
+pub struct RecordView<'a> {
+    /// Size in bytes of the length header that preceded `value`.
+    pub header_len: usize,
+    /// The record's payload, borrowed directly from the input buffer.
+    pub value: &'a [u8],
+    /// Absolute byte offset of this record's header in the input buffer.
+    pub offset: usize,
+}
+
+impl<'a> RecordView<'a> {
+    /// Framing sizes for this record, for computing the total space it
+    /// occupies without materializing `value`.
+    pub fn payload_info(&self) -> PayloadInfo {
+        PayloadInfo {
+            header_len: self.header_len,
+            value_len: self.value.len(),
+        }
+    }
+}
+
+/// Sizes needed to compute a record's total framed size in place.
+pub struct PayloadInfo {
+    pub header_len: usize,
+    pub value_len: usize,
+}
+
+impl PayloadInfo {
+    pub fn total_len(&self) -> usize {
+        self.header_len + self.value_len
+    }
+}
+
+impl<C: LengthCodec> RecordParserStage<C> {
+    /// Like `run`, but borrows each payload from `input` instead of copying
+    /// it, so filtering stages can run over the records for free.
+    pub fn parse_views<'a>(&self, input: &'a [u8]) -> Result<Vec<RecordView<'a>>> {
+        let mut res = Vec::new();
+        let mut i = 0;
+
+        while i < input.len() {
+            let (len, header_len) = match C::decode(&input[i..]) {
+                Ok(decoded) => decoded,
+                Err(DecodeError::Incomplete) => break,
+                Err(DecodeError::Invalid) => {
+                    return Err(PipelineError::InvalidLength { offset: i });
+                }
+            };
+            let header_offset = i;
+            i += header_len;
+
+            let end = match i.checked_add(len) {
+                Some(end) if end <= input.len() => end,
+                _ => {
+                    return Err(PipelineError::TruncatedRecord {
+                        offset: header_offset,
+                        expected: len,
+                        available: input.len() - i,
+                    });
+                }
+            };
+
+            res.push(RecordView {
+                header_len,
+                value: &input[i..end],
+                offset: header_offset,
+            });
+            i = end;
+        }
+
+        if i != input.len() {
+            return Err(PipelineError::TrailingBytes {
+                offset: i,
+                extra: input.len() - i,
+            });
         }
 
         Ok(res)
     }
 }
 
+//
+// ---------- Writer Stage ------------
+//
+/// Inverse of `RecordParserStage`: serializes records back to the
+/// length-prefixed wire format using the same codec `C`, so a pipeline can
+/// transform records and then re-emit them for output or forwarding.
+pub struct RecordWriterStage<C = FixedU32Be> {
+    _codec: PhantomData<C>,
+}
+
+impl<C> Default for RecordWriterStage<C> {
+    fn default() -> Self {
+        Self {
+            _codec: PhantomData,
+        }
+    }
+}
+
+impl<C> RecordWriterStage<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: LengthCodec> Stage<Vec<Record>, Vec<u8>> for RecordWriterStage<C> {
+    fn run(&self, input: Vec<Record>) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+
+        for rec in input {
+            C::encode(rec.len(), &mut out);
+            out.extend_from_slice(&rec);
+        }
+
+        Ok(out)
+    }
+}
+
+//
+// ---------- Streaming Parser ------------
+//
+/// Incremental counterpart to `RecordParserStage` for inputs that arrive in
+/// chunks (sockets, large files) rather than as a single in-memory buffer.
+///
+/// Uses the same framing as `RecordParserStage`:
+///    [length header, per `C`] [payload]  * repeated
+///
+/// Bytes that don't yet form a complete header+payload are held in `saved`
+/// until the next `feed` call supplies the rest.
+pub struct StreamingRecordParser<C = FixedU32Be> {
+    saved: Vec<u8>,
+    /// Absolute offset of `saved[0]` in the overall stream, for error offsets.
+    consumed: usize,
+    _codec: PhantomData<C>,
+}
+
+impl<C> Default for StreamingRecordParser<C> {
+    fn default() -> Self {
+        Self {
+            saved: Vec::new(),
+            consumed: 0,
+            _codec: PhantomData,
+        }
+    }
+}
+
+impl<C: LengthCodec> StreamingRecordParser<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bytes currently buffered waiting for more data.
+    pub fn saved_len(&self) -> usize {
+        self.saved.len()
+    }
+
+    /// Feed the next chunk of bytes, returning any records that became
+    /// complete as a result.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<Record>> {
+        self.saved.extend_from_slice(chunk);
+
+        let mut res = Vec::new();
+        let mut i = 0;
+
+        loop {
+            let (len, header_len) = match C::decode(&self.saved[i..]) {
+                Ok(decoded) => decoded,
+                Err(DecodeError::Incomplete) => break,
+                Err(DecodeError::Invalid) => {
+                    return Err(PipelineError::InvalidLength {
+                        offset: self.consumed + i,
+                    });
+                }
+            };
+
+            let body_start = i + header_len;
+            let end = match body_start.checked_add(len) {
+                Some(end) if end <= self.saved.len() => end,
+                _ => break,
+            };
+
+            let payload = self.saved[body_start..end].to_vec();
+            i = end;
+
+            res.push(payload);
+        }
+
+        self.saved.drain(..i);
+        self.consumed += i;
+
+        Ok(res)
+    }
+
+    /// Signal end-of-stream. Errors if bytes remain buffered, since that
+    /// means the input was truncated mid-record.
+    /// The error is the one `RecordParserStage::run` reports for the same
+    /// leftover bytes at the end of its input.
+    pub fn finish(self) -> Result<()> {
+        if self.saved.is_empty() {
+            return Ok(());
+        }
+
+        Err(match C::decode(&self.saved) {
+            Ok((len, header_len)) => PipelineError::TruncatedRecord {
+                offset: self.consumed,
+                expected: len,
+                available: self.saved.len() - header_len,
+            },
+            Err(DecodeError::Invalid) => PipelineError::InvalidLength { offset: self.consumed },
+            Err(DecodeError::Incomplete) => PipelineError::TrailingBytes {
+                offset: self.consumed,
+                extra: self.saved.len(),
+            },
+        })
+    }
+}
+
+//
+// ---------- Compression Stages ------------
+//
+/// Whole-frame (de)compression, so a pipeline can consume compressed record
+/// files between `FileReaderStage` and `RecordParserStage`. Analogous to
+/// `LengthCodec`, but for an entire compressed frame rather than a length
+/// prefix.
+pub trait FrameCodec {
+    /// Compress `data` into a single frame.
+    fn compress(data: &[u8]) -> anyhow::Result<Vec<u8>>;
+
+    /// Decompress a single frame starting at the beginning of `input`,
+    /// returning the decompressed bytes and the number of input bytes the
+    /// frame consumed. Must stop exactly at the frame's own end marker and
+    /// must not read or discard bytes belonging to whatever follows.
+    fn decompress(input: &[u8]) -> anyhow::Result<(Vec<u8>, usize)>;
+}
+
+/// Gzip framing, via `flate2`.
+pub struct Gzip;
+
+impl FrameCodec for Gzip {
+    fn compress(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data)?;
+        Ok(encoder.finish()?)
+    }
+
+    fn decompress(input: &[u8]) -> anyhow::Result<(Vec<u8>, usize)> {
+        use flate2::bufread::GzDecoder;
+        use std::io::{Cursor, Read};
+
+        let mut cursor = Cursor::new(input);
+        let mut out = Vec::new();
+        GzDecoder::new(&mut cursor).read_to_end(&mut out)?;
+        Ok((out, cursor.position() as usize))
+    }
+}
+
+/// Zstandard framing, via `zstd`.
+pub struct Zstd;
+
+impl FrameCodec for Zstd {
+    fn compress(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        Ok(zstd::stream::encode_all(data, 0)?)
+    }
+
+    fn decompress(input: &[u8]) -> anyhow::Result<(Vec<u8>, usize)> {
+        // The high-level `zstd::stream::read::Decoder` buffers ahead inside
+        // its own reader even with `single_frame()`, so the consumed count
+        // it leaves behind over-reports into whatever follows the frame.
+        // Drive the low-level streaming API directly instead: `Operation::run`
+        // returns 0 exactly when the frame is fully decoded, at which point
+        // `InBuffer`'s position is the true number of input bytes consumed.
+        use zstd::stream::raw::{Decoder as RawDecoder, InBuffer, Operation, OutBuffer};
+
+        let mut decoder = RawDecoder::new()?;
+        let mut in_buffer = InBuffer::around(input);
+        let mut out = Vec::new();
+        let mut chunk: Vec<u8> = Vec::with_capacity(64 * 1024);
+
+        loop {
+            chunk.clear();
+            let mut out_buffer = OutBuffer::around(&mut chunk);
+            let remaining_hint = decoder.run(&mut in_buffer, &mut out_buffer)?;
+            out.extend_from_slice(out_buffer.as_slice());
+
+            if remaining_hint == 0 {
+                break;
+            }
+        }
+
+        Ok((out, in_buffer.pos()))
+    }
+}
+
+/// Decompresses the leading compressed frame of its input. Trailing bytes
+/// past the frame (a concatenated frame, or other non-compressed data) are
+/// left unread by `F::decompress` and simply ignored here; call
+/// `F::decompress` directly if the consumed-byte count is needed.
+pub struct DecompressStage<F> {
+    _codec: PhantomData<F>,
+}
+
+impl<F> Default for DecompressStage<F> {
+    fn default() -> Self {
+        Self {
+            _codec: PhantomData,
+        }
+    }
+}
+
+impl<F> DecompressStage<F> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<F: FrameCodec> Stage<Vec<u8>, Vec<u8>> for DecompressStage<F> {
+    fn run(&self, input: Vec<u8>) -> Result<Vec<u8>> {
+        let (decompressed, _consumed) = F::decompress(&input)?;
+        Ok(decompressed)
+    }
+}
+
+/// Compresses its input into a single frame.
+pub struct CompressStage<F> {
+    _codec: PhantomData<F>,
+}
+
+impl<F> Default for CompressStage<F> {
+    fn default() -> Self {
+        Self {
+            _codec: PhantomData,
+        }
+    }
+}
+
+impl<F> CompressStage<F> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<F: FrameCodec> Stage<Vec<u8>, Vec<u8>> for CompressStage<F> {
+    fn run(&self, input: Vec<u8>) -> Result<Vec<u8>> {
+        Ok(F::compress(&input)?)
+    }
+}
+
 //
 // ---------- Business Logic Stage ------------
 //
@@ -74,6 +583,28 @@ impl Stage<Vec<Record>, Vec<Record>> for BusinessLogicStage {
     }
 }
 
+/// Same rules as the owned-`Vec<Record>` impl above, but over borrowed
+/// `RecordView`s: the `len <= 3` filter costs nothing since it never touches
+/// `value`, and only the records that survive it get uppercased (the only
+/// step that truly needs an allocation).
+impl<'a> Stage<&'a [RecordView<'a>], Vec<Record>> for BusinessLogicStage {
+    fn run(&self, input: &'a [RecordView<'a>]) -> Result<Vec<Record>> {
+        let mut out = Vec::new();
+
+        for view in input {
+            if view.value.len() <= 3 {
+                continue;
+            }
+
+            if let Ok(s) = std::str::from_utf8(view.value) {
+                out.push(s.to_uppercase().into_bytes());
+            }
+        }
+
+        Ok(out)
+    }
+}
+
 pub struct Pipeline<S> {
     pub stage: S,
 }
@@ -122,3 +653,179 @@ impl<S> Pipeline<S> {
         self.stage.run(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trips<C: LengthCodec>(records: Vec<Record>) -> bool {
+        let bytes = RecordWriterStage::<C>::default().run(records.clone()).unwrap();
+        let parsed = RecordParserStage::<C>::default().run(bytes).unwrap();
+        parsed == records
+    }
+
+    #[test]
+    fn write_then_parse_round_trips_fixed_u32be() {
+        let records = vec![b"hello".to_vec(), b"".to_vec(), b"world!!".to_vec()];
+        assert!(round_trips::<FixedU32Be>(records));
+    }
+
+    #[test]
+    fn write_then_parse_round_trips_varint_leb128() {
+        let records = vec![b"hello".to_vec(), b"".to_vec(), vec![0u8; 300]];
+        assert!(round_trips::<VarintLeb128>(records));
+    }
+
+    #[test]
+    fn run_reports_invalid_length_instead_of_panicking_on_oversized_varint() {
+        // 10-byte varint header, continuation bit set on every byte, so it
+        // never terminates and hits the `i >= 10` overflow guard.
+        let input = vec![0xFFu8; 10];
+
+        let err = RecordParserStage::<VarintLeb128>::default()
+            .run(input)
+            .unwrap_err();
+        assert!(matches!(err, PipelineError::InvalidLength { offset: 0 }));
+    }
+
+    #[test]
+    fn feed_reports_invalid_length_instead_of_hanging_on_oversized_varint() {
+        let input = vec![0xFFu8; 10];
+
+        let err = StreamingRecordParser::<VarintLeb128>::default()
+            .feed(&input)
+            .unwrap_err();
+        assert!(matches!(err, PipelineError::InvalidLength { offset: 0 }));
+    }
+
+    #[test]
+    fn feed_assembles_a_record_split_across_multiple_chunks() {
+        let mut encoded = Vec::new();
+        FixedU32Be::encode(5, &mut encoded);
+        encoded.extend_from_slice(b"hello");
+
+        let mut parser = StreamingRecordParser::<FixedU32Be>::default();
+
+        // Split mid-header, then mid-payload, feeding one byte at a time.
+        let mut records = Vec::new();
+        for byte in &encoded {
+            records.extend(parser.feed(&[*byte]).unwrap());
+        }
+
+        assert_eq!(records, vec![b"hello".to_vec()]);
+        assert_eq!(parser.saved_len(), 0);
+    }
+
+    #[test]
+    fn saved_len_reports_buffered_bytes_until_the_record_completes() {
+        let mut encoded = Vec::new();
+        FixedU32Be::encode(5, &mut encoded);
+        encoded.extend_from_slice(b"hello");
+
+        let mut parser = StreamingRecordParser::<FixedU32Be>::default();
+
+        // Full header plus a partial payload: buffered, not yet a record.
+        assert!(parser.feed(&encoded[..6]).unwrap().is_empty());
+        assert_eq!(parser.saved_len(), 6);
+
+        // The rest of the payload completes the record and drains the buffer.
+        let records = parser.feed(&encoded[6..]).unwrap();
+        assert_eq!(records, vec![b"hello".to_vec()]);
+        assert_eq!(parser.saved_len(), 0);
+    }
+
+    #[test]
+    fn finish_succeeds_once_every_fed_record_is_complete() {
+        let mut encoded = Vec::new();
+        FixedU32Be::encode(5, &mut encoded);
+        encoded.extend_from_slice(b"hello");
+
+        let mut parser = StreamingRecordParser::<FixedU32Be>::default();
+        parser.feed(&encoded).unwrap();
+
+        assert!(parser.finish().is_ok());
+    }
+
+    #[test]
+    fn finish_reports_truncated_record_when_bytes_remain_buffered() {
+        let mut encoded = Vec::new();
+        FixedU32Be::encode(5, &mut encoded);
+        encoded.extend_from_slice(b"hel"); // payload cut short
+
+        let mut parser = StreamingRecordParser::<FixedU32Be>::default();
+        parser.feed(&encoded).unwrap();
+
+        let err = parser.finish().unwrap_err();
+        assert!(matches!(
+            err,
+            PipelineError::TruncatedRecord { offset: 0, expected: 5, available: 3 }
+        ));
+    }
+
+    #[test]
+    fn gzip_decompress_does_not_overread_into_trailing_bytes() {
+        let payload = b"pipeline stage payload".to_vec();
+        let mut frame = Gzip::compress(&payload).unwrap();
+        let extra = b"trailing-non-compressed-data";
+        frame.extend_from_slice(extra);
+
+        let (decompressed, consumed) = Gzip::decompress(&frame).unwrap();
+        assert_eq!(decompressed, payload);
+        assert_eq!(&frame[consumed..], extra);
+    }
+
+    #[test]
+    fn zstd_decompress_does_not_overread_into_trailing_bytes() {
+        let payload = b"pipeline stage payload".to_vec();
+        let mut frame = Zstd::compress(&payload).unwrap();
+        let extra = b"trailing-non-compressed-data";
+        frame.extend_from_slice(extra);
+
+        let (decompressed, consumed) = Zstd::decompress(&frame).unwrap();
+        assert_eq!(decompressed, payload);
+        assert_eq!(&frame[consumed..], extra);
+    }
+
+    #[test]
+    fn parse_views_matches_run_payloads_and_offsets() {
+        let records = vec![b"hello".to_vec(), b"".to_vec(), b"world!!".to_vec()];
+        let bytes = RecordWriterStage::<FixedU32Be>::default()
+            .run(records.clone())
+            .unwrap();
+
+        let views = RecordParserStage::<FixedU32Be>::default()
+            .parse_views(&bytes)
+            .unwrap();
+
+        let values: Vec<Record> = views.iter().map(|v| v.value.to_vec()).collect();
+        assert_eq!(values, records);
+
+        // Each view's header starts right where the previous record ended.
+        let mut expected_offset = 0;
+        for (view, record) in views.iter().zip(&records) {
+            assert_eq!(view.offset, expected_offset);
+            assert_eq!(view.header_len, 4);
+            assert_eq!(view.value, record.as_slice());
+            expected_offset += view.payload_info().total_len();
+        }
+        assert_eq!(expected_offset, bytes.len());
+    }
+
+    #[test]
+    fn business_logic_over_views_matches_owned_business_logic() {
+        let records = vec![b"hi".to_vec(), b"hello".to_vec(), b"world!!".to_vec()];
+        let bytes = RecordWriterStage::<FixedU32Be>::default()
+            .run(records.clone())
+            .unwrap();
+
+        let views = RecordParserStage::<FixedU32Be>::default()
+            .parse_views(&bytes)
+            .unwrap();
+
+        let from_views = BusinessLogicStage.run(views.as_slice()).unwrap();
+        let from_owned = BusinessLogicStage.run(records).unwrap();
+
+        assert_eq!(from_views, from_owned);
+        assert_eq!(from_views, vec![b"HELLO".to_vec(), b"WORLD!!".to_vec()]);
+    }
+}